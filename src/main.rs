@@ -1,22 +1,68 @@
 mod math;
+mod seal;
+mod secret;
+mod slip39;
 use bip39;
-use math::field;
 use math::lagrange;
 use rand_core::{OsRng, RngCore};
+use secret::SecretBytes;
+use std::path::{Path, PathBuf};
 use std::{error::Error, io};
 use structopt::StructOpt;
 
 #[derive(Debug)]
-struct MainError(String);
+enum MainError {
+    /// A generic, human-readable failure.
+    Message(String),
+    /// `mlock` failed for the given address and byte count.
+    Mlock { addr: usize, len: usize },
+    /// `munlock` failed for the given address and byte count.
+    Munlock { addr: usize, len: usize },
+    /// A share beyond the threshold disagreed with the reconstructed secret, or
+    /// two shares carried the same index.
+    InconsistentShare { index: u8 },
+}
 
 impl std::fmt::Display for MainError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        match self {
+            MainError::Message(msg) => write!(f, "{}", msg),
+            MainError::Mlock { addr, len } => {
+                write!(f, "failed to mlock {} bytes at {:#x}", len, addr)
+            }
+            MainError::Munlock { addr, len } => {
+                write!(f, "failed to munlock {} bytes at {:#x}", len, addr)
+            }
+            MainError::InconsistentShare { index } => {
+                write!(f, "share {} is inconsistent with the others", index)
+            }
+        }
     }
 }
 
 impl Error for MainError {}
 
+/// The on-the-wire encoding used for shares.
+#[derive(Debug, Clone, Copy)]
+enum Format {
+    /// The default: a decimal index followed by a BIP39 mnemonic.
+    Default,
+    /// A self-describing SLIP-0039 share with a Reed–Solomon checksum.
+    Slip39,
+}
+
+impl std::str::FromStr for Format {
+    type Err = MainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Format::Default),
+            "slip39" => Ok(Format::Slip39),
+            other => Err(MainError::Message(format!("unknown format: {}", other))),
+        }
+    }
+}
+
 #[derive(Debug, StructOpt)]
 #[structopt(
     name = "seed-split",
@@ -25,6 +71,8 @@ impl Error for MainError {}
 enum Opt {
     /// Generate a random seed phrase.
     Random,
+    /// Generate an X25519 keypair for `split --recipient`/`combine --secret-key`.
+    Keygen,
     /// Split a seed phrase into multiple shares.
     Split {
         /// The number of shares needed to recreate the seed.
@@ -33,17 +81,46 @@ enum Opt {
         /// The total number of shares.
         #[structopt(short = "n", long = "count")]
         count: u8,
+        /// The share encoding to emit.
+        #[structopt(long = "format", default_value = "default")]
+        format: Format,
+        /// Seal each share to a recipient X25519 public key, given as a
+        /// mnemonic. Must be supplied exactly `count` times, once per share.
+        #[structopt(long = "recipient")]
+        recipients: Vec<String>,
+        /// Read the secret to split as raw bytes from this file, instead of a
+        /// BIP39 seed phrase on stdin. This is the only way to split a secret
+        /// that isn't a standard BIP39 seed phrase: a 24-word-plus seed, a
+        /// passphrase-wrapped seed, or arbitrary key material. The default
+        /// format prints each share as a BIP39 mnemonic, so it still accepts
+        /// only a standard BIP39 entropy length (16 to 32 bytes, a multiple of
+        /// 4); `--format slip39` and `--recipient` accept any length.
+        #[structopt(long = "input-file", parse(from_os_str))]
+        input_file: Option<PathBuf>,
     },
     /// Combine multiple shares into a seed phrase.
     Combine {
         /// The number of shares being combined
         #[structopt(name = "threshold")]
         threshold: u8,
+        /// The share encoding to read.
+        #[structopt(long = "format", default_value = "default")]
+        format: Format,
+        /// Unseal shares with a recipient X25519 secret key, given as a
+        /// mnemonic. May be supplied more than once to unseal shares addressed
+        /// to different recipients.
+        #[structopt(long = "secret-key")]
+        secret_keys: Vec<String>,
+        /// Write the reconstructed secret's raw bytes to this file, instead of
+        /// printing it as a BIP39 seed phrase. Required when the secret isn't
+        /// a standard BIP39 entropy length, e.g. one split with `--input-file`.
+        #[structopt(long = "output-file", parse(from_os_str))]
+        output_file: Option<PathBuf>,
     },
 }
 
 fn random() -> Result<(), Box<dyn Error>> {
-    let mut entropy_bytes = [0u8; 16];
+    let mut entropy_bytes = SecretBytes::zeroed(16)?;
     OsRng.fill_bytes(&mut entropy_bytes);
     let seed_phrase = bip39::Mnemonic::from_entropy(&entropy_bytes)
         .expect("failed to generate mnemonic from entropy");
@@ -51,127 +128,435 @@ fn random() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn continue_split_128(data: [u8; 16], sharing: lagrange::Sharing) -> Result<(), Box<dyn Error>> {
-    let secret = field::GF128::from(data);
-    let shares = lagrange::split(&mut OsRng, secret, sharing);
-    for (i, share) in shares {
-        let share_bytes: [u8; 16] = share.into();
+/// Generate a fresh recipient keypair and print both mnemonics.
+///
+/// The public key mnemonic is the one to hand out for `split --recipient`;
+/// the secret key mnemonic is the one to keep for `combine --secret-key`. The
+/// two must come from the same `keygen` run, since pairing an unrelated
+/// mnemonic with either half does not produce a matching keypair.
+fn keygen() -> Result<(), Box<dyn Error>> {
+    let (secret_key, public_key) = seal::generate_keypair()?;
+    println!("Secret Key:\n{}", secret_key);
+    println!("Public Key:\n{}", public_key);
+    Ok(())
+}
+
+/// Print the shares produced by `split` as plain indexed BIP39 mnemonics.
+fn emit_default(shares: Vec<(lagrange::Index, SecretBytes)>) -> Result<(), Box<dyn Error>> {
+    for (i, share_bytes) in shares {
         let mnemonic = bip39::Mnemonic::from_entropy(&share_bytes)?;
         println!("{} {}", u8::from(i) + 1, mnemonic);
     }
     Ok(())
 }
 
-fn continue_split_256(data: [u8; 32], sharing: lagrange::Sharing) -> Result<(), Box<dyn Error>> {
-    let secret = field::GF256::from(data);
-    let shares = lagrange::split(&mut OsRng, secret, sharing);
-    for (i, share) in shares {
-        let share_bytes: [u8; 32] = share.into();
-        let mnemonic = bip39::Mnemonic::from_entropy(&share_bytes)?;
-        println!("{} {}", u8::from(i) + 1, mnemonic);
+/// Print the shares produced by `split` as SLIP-0039 mnemonics.
+///
+/// An extra digest share lets `combine` confirm the reconstructed secret.
+fn emit_slip39(
+    secret: &SecretBytes,
+    threshold: u8,
+    shares: Vec<(lagrange::Index, SecretBytes)>,
+) -> Result<(), Box<dyn Error>> {
+    // The threshold and member index each occupy only 5 bits on the wire, and
+    // index 0 is reserved for the digest share, so a member index must fit in
+    // 1..=31.
+    if threshold > slip39::MAX_INDEX {
+        return Err(Box::new(MainError::Message(format!(
+            "threshold {} is too large for a slip39 share",
+            threshold
+        ))));
+    }
+    if shares.len() as u64 > u64::from(slip39::MAX_INDEX) {
+        return Err(Box::new(MainError::Message(format!(
+            "count {} is too large for a slip39 share",
+            shares.len()
+        ))));
+    }
+    let identifier = (OsRng.next_u32() & ((1 << 15) - 1)) as u16;
+    let size = secret.len();
+    for (i, share_bytes) in shares {
+        // `Share.value` is a plain Vec<u8>, so it's wiped explicitly once
+        // printed instead of being left for an ordinary, non-zeroizing drop.
+        let mut share = slip39::Share {
+            identifier,
+            threshold,
+            index: u8::from(i) + 1,
+            value: share_bytes.to_vec(),
+        };
+        println!("{}", share.to_mnemonic());
+        secret::zeroize(&mut share.value);
+    }
+    // The digest share carries `random_key || HMAC-SHA256(random_key, secret)`,
+    // two halves that together fill one share value's length, up to 64 bytes:
+    // each half is capped at the 32 bytes of an HMAC-SHA256 tag, so that
+    // `verify_digest` can split the value back in half for a longer secret.
+    let half = (size / 2).min(32);
+    let mut key = SecretBytes::zeroed(half)?;
+    OsRng.fill_bytes(&mut key);
+    let mut value = key.to_vec();
+    value.extend_from_slice(&slip39::digest(&key, secret, size - half));
+    let mut digest_share = slip39::Share {
+        identifier,
+        threshold,
+        index: slip39::DIGEST_INDEX,
+        value,
+    };
+    println!("{}", digest_share.to_mnemonic());
+    secret::zeroize(&mut digest_share.value);
+    Ok(())
+}
+
+/// Seal each share to its matching recipient and print the resulting mnemonics.
+///
+/// Share `i` is sealed to `recipients[i]`, so there must be one recipient per
+/// share.
+fn emit_sealed(
+    recipients: &[x25519_dalek::PublicKey],
+    shares: Vec<(lagrange::Index, SecretBytes)>,
+) -> Result<(), Box<dyn Error>> {
+    for (i, share_bytes) in shares {
+        let n = u8::from(i);
+        println!("{}", seal::seal(&recipients[usize::from(n)], n + 1, &share_bytes)?);
     }
     Ok(())
 }
 
-fn split(threshold: u8, count: u8) -> Result<(), Box<dyn Error>> {
+/// Share `data` byte-by-byte over GF(2^8) and emit the shares in `format`.
+fn continue_split(
+    data: SecretBytes,
+    sharing: lagrange::Sharing,
+    format: Format,
+    recipients: &[x25519_dalek::PublicKey],
+) -> Result<(), Box<dyn Error>> {
+    let share_bytes = lagrange::split_bytes(&mut OsRng, &data, sharing)?;
+    if !recipients.is_empty() {
+        return emit_sealed(recipients, share_bytes);
+    }
+    match format {
+        Format::Default => emit_default(share_bytes),
+        Format::Slip39 => emit_slip39(&data, sharing.threshold(), share_bytes),
+    }
+}
+
+fn split(
+    threshold: u8,
+    count: u8,
+    format: Format,
+    recipients: Vec<String>,
+    input_file: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
     if count <= 0 {
-        return Err(Box::new(MainError(format!("count must be at least 1"))));
+        return Err(Box::new(MainError::Message(format!(
+            "count must be at least 1"
+        ))));
     }
     if threshold > count {
-        return Err(Box::new(MainError(format!("threshold must be <= count"))));
+        return Err(Box::new(MainError::Message(format!(
+            "threshold must be <= count"
+        ))));
     }
-    let sharing = lagrange::Sharing::new(threshold, count);
-    println!("Seed Phrase:");
-    let mut buf = String::new();
-    io::stdin().read_line(&mut buf)?;
-    let mnemonic = bip39::Mnemonic::parse(&buf)?;
-    let entropy = mnemonic.to_entropy();
-    if entropy.len() <= 16 {
-        let mut data = [0u8; 16];
-        data[..entropy.len()].copy_from_slice(&entropy);
-        continue_split_128(data, sharing)
-    } else if entropy.len() <= 32 {
-        let mut data = [0u8; 32];
-        data.copy_from_slice(&entropy);
-        continue_split_256(data, sharing)
-    } else {
-        Err(Box::new(MainError(format!(
-            "excessive seed length: {} bytes",
-            entropy.len()
-        ))))
+    if !recipients.is_empty() && matches!(format, Format::Slip39) {
+        return Err(Box::new(MainError::Message(
+            "sealing shares is not compatible with --format slip39".into(),
+        )));
+    }
+    if !recipients.is_empty() && recipients.len() != usize::from(count) {
+        return Err(Box::new(MainError::Message(format!(
+            "expected {} recipients, one per share, got {}",
+            count,
+            recipients.len()
+        ))));
     }
+    let recipients = recipients
+        .iter()
+        .map(|m| seal::parse_public_key(m))
+        .collect::<Result<Vec<_>, _>>()?;
+    let sharing = lagrange::Sharing::new(threshold, count);
+    let data = match input_file {
+        Some(path) => {
+            // `fs::read` hands back a plain, unprotected Vec<u8> holding the
+            // whole secret; copy it into SecretBytes and wipe the original
+            // before it drops.
+            let mut raw = std::fs::read(path)?;
+            let locked = SecretBytes::from_slice(&raw)?;
+            secret::zeroize(&mut raw);
+            locked
+        }
+        None => {
+            println!("Seed Phrase:");
+            let mut buf = String::new();
+            io::stdin().read_line(&mut buf)?;
+            let mnemonic = bip39::Mnemonic::parse(&buf)?;
+            SecretBytes::from_slice(&mnemonic.to_entropy())?
+        }
+    };
+    continue_split(data, sharing, format, &recipients)
 }
 
 fn parse_indexed_mnemonic(s: &str) -> Result<(u8, bip39::Mnemonic), Box<dyn Error>> {
     let space_position = s
         .chars()
         .position(|c| c == ' ')
-        .ok_or(Box::new(MainError("invalid share format".into())))?;
+        .ok_or(Box::new(MainError::Message("invalid share format".into())))?;
     let index: u8 = s[..space_position].parse()?;
     if index < 1 {
-        return Err(Box::new(MainError("share index must be >= 1".into())));
+        return Err(Box::new(MainError::Message(
+            "share index must be >= 1".into(),
+        )));
     }
     let mnemonic = bip39::Mnemonic::parse(&s[space_position + 1..])?;
     Ok((index - 1, mnemonic))
 }
 
-fn combine(threshold: u8) -> Result<(), Box<dyn Error>> {
+fn combine(
+    threshold: u8,
+    format: Format,
+    secret_keys: Vec<String>,
+    output_file: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    if !secret_keys.is_empty() {
+        if matches!(format, Format::Slip39) {
+            return Err(Box::new(MainError::Message(
+                "unsealing shares is not compatible with --format slip39".into(),
+            )));
+        }
+        return combine_sealed(threshold, secret_keys, output_file.as_deref());
+    }
+    match format {
+        Format::Default => combine_default(threshold, output_file.as_deref()),
+        Format::Slip39 => combine_slip39(threshold, output_file.as_deref()),
+    }
+}
+
+/// Reconstruct the secret from sealed shares, decrypting with the recipient
+/// secret keys before running the usual Lagrange reconstruction.
+fn combine_sealed(
+    threshold: u8,
+    secret_keys: Vec<String>,
+    output_file: Option<&Path>,
+) -> Result<(), Box<dyn Error>> {
+    if threshold < 1 {
+        return Err(Box::new(MainError::Message(
+            "threshold must be at least 1".into(),
+        )));
+    }
+    let keys = secret_keys
+        .iter()
+        .map(|m| seal::parse_secret_key(m))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Read every sealed share on stdin; extras beyond the threshold are checked
+    // against the reconstructed polynomial.
+    let mut parsed: Vec<(lagrange::Index, SecretBytes)> = Vec::with_capacity(threshold as usize);
+    let stdin = io::stdin();
+    let mut buf = String::new();
+    loop {
+        buf.clear();
+        if stdin.read_line(&mut buf)? == 0 {
+            break;
+        }
+        if buf.trim().is_empty() {
+            continue;
+        }
+        let mut opened = None;
+        for key in &keys {
+            if let Some(share) = seal::unseal(key, buf.trim())? {
+                opened = Some(share);
+                break;
+            }
+        }
+        match opened {
+            Some((index, value)) => parsed.push(((index - 1).into(), SecretBytes::from_slice(&value)?)),
+            None => {
+                return Err(Box::new(MainError::Message(
+                    "no secret key could unseal a share".into(),
+                )))
+            }
+        }
+    }
+    reconstruct_and_print(threshold, parsed, output_file)
+}
+
+/// Reconstruct the secret from collected share values, verifying any shares
+/// beyond `threshold`.
+///
+/// Each entry pairs a share's (0-based) index with its value bytes. Every byte
+/// column is interpolated on its own: the first `threshold` shares fix the
+/// polynomial and any extras are checked against it.
+fn reconstruct_secret(
+    threshold: u8,
+    parsed: &[(lagrange::Index, SecretBytes)],
+) -> Result<SecretBytes, Box<dyn Error>> {
+    if parsed.len() < usize::from(threshold) {
+        return Err(Box::new(MainError::Message(format!(
+            "need at least {} shares, only got {}",
+            threshold,
+            parsed.len()
+        ))));
+    }
+    let size = parsed[0].1.len();
+    if !parsed.iter().all(|(_, value)| value.len() == size) {
+        return Err(Box::new(MainError::Message(
+            "shares have inconsistent sizes".into(),
+        )));
+    }
+    Ok(lagrange::reconstruct_bytes_checked(usize::from(threshold), parsed)?)
+}
+
+/// Print a reconstructed secret as a BIP39 seed phrase, or, when `output_file`
+/// is given, write its raw bytes to `output_file` instead. A secret that isn't
+/// a standard BIP39 entropy length is an error unless `output_file` is given.
+fn emit_reconstructed(secret: &SecretBytes, output_file: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    match (bip39::Mnemonic::from_entropy(secret), output_file) {
+        (Ok(mnemonic), None) => println!("Reconstructed:\n{}", mnemonic),
+        (_, Some(path)) => {
+            std::fs::write(path, &**secret)?;
+            println!("Reconstructed secret written to {}", path.display());
+        }
+        (Err(_), None) => {
+            return Err(Box::new(MainError::Message(
+                "reconstructed secret is not a standard BIP39 entropy length; rerun with --output-file to write the raw bytes".into(),
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Reconstruct the secret from collected share values, verifying any shares
+/// beyond `threshold`, and print the recovered seed phrase (or write its raw
+/// bytes to `output_file`, when given).
+///
+/// If no shares beyond the threshold are supplied the result is flagged as
+/// unverified.
+fn reconstruct_and_print(
+    threshold: u8,
+    parsed: Vec<(lagrange::Index, SecretBytes)>,
+    output_file: Option<&Path>,
+) -> Result<(), Box<dyn Error>> {
+    if parsed.len() == usize::from(threshold) {
+        eprintln!(
+            "warning: only {} shares supplied, the result could not be verified",
+            threshold
+        );
+    }
+    let secret = reconstruct_secret(threshold, &parsed)?;
+    emit_reconstructed(&secret, output_file)
+}
+
+fn combine_default(threshold: u8, output_file: Option<&Path>) -> Result<(), Box<dyn Error>> {
     if threshold < 1 {
-        return Err(Box::new(MainError("threshold must be at least 1".into())));
+        return Err(Box::new(MainError::Message(
+            "threshold must be at least 1".into(),
+        )));
     }
-    let mut parsed: Vec<(u8, ([u8; 33], usize))> = Vec::with_capacity(threshold as usize);
+    // Read every share on stdin; supplying more than the threshold lets the
+    // extras be checked against the reconstructed polynomial.
+    let mut parsed: Vec<(lagrange::Index, SecretBytes)> = Vec::with_capacity(threshold as usize);
+    let stdin = io::stdin();
     let mut buf = String::new();
-    for _ in 0..threshold {
+    loop {
         buf.clear();
-        io::stdin().read_line(&mut buf)?;
+        if stdin.read_line(&mut buf)? == 0 {
+            break;
+        }
+        if buf.trim().is_empty() {
+            continue;
+        }
         let (index, mnemonic) = parse_indexed_mnemonic(&buf)?;
-        parsed.push((index, mnemonic.to_entropy_array()));
+        let (arr, size) = mnemonic.to_entropy_array();
+        parsed.push((index.into(), SecretBytes::from_slice(&arr[..size])?));
     }
-    let (_, (_, size)) = parsed[0];
-    if !parsed.iter().all(|(_, (_, size2))| *size2 == size) {
-        return Err(Box::new(MainError(
-            "seed phrases have inconsistent sizes".into(),
+    reconstruct_and_print(threshold, parsed, output_file)
+}
+
+fn combine_slip39(threshold: u8, output_file: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    if threshold < 1 {
+        return Err(Box::new(MainError::Message(
+            "threshold must be at least 1".into(),
         )));
     }
-    if size <= 16 {
-        let mut shares: Vec<(lagrange::Index, field::GF128)> =
-            Vec::with_capacity(threshold as usize);
-        for (i, (arr, _)) in parsed {
-            let mut data = [0u8; 16];
-            data[..size].copy_from_slice(&arr[..size]);
-            let fel = field::GF128::from(data);
-            shares.push((i.into(), fel));
-        }
-        let secret_data: [u8; 16] = lagrange::reconstruct(&shares).into();
-        let mnemonic = bip39::Mnemonic::from_entropy(&secret_data).unwrap();
-        println!("Reconstructed:\n{}", mnemonic);
-        Ok(())
-    } else if size <= 32 {
-        let mut shares: Vec<(lagrange::Index, field::GF256)> =
-            Vec::with_capacity(threshold as usize);
-        for (i, (arr, _)) in parsed {
-            let mut data = [0u8; 32];
-            data[..size].copy_from_slice(&arr[..size]);
-            let fel = field::GF256::from(data);
-            shares.push((i.into(), fel));
-        }
-        let secret_data: [u8; 32] = lagrange::reconstruct(&shares).into();
-        let mnemonic = bip39::Mnemonic::from_entropy(&secret_data).unwrap();
-        println!("Reconstructed:\n{}", mnemonic);
-        Ok(())
-    } else {
-        Err(Box::new(MainError(format!(
-            "excessive seed length: {} bytes",
-            size
-        ))))
+    // SLIP-0039 shares are self-describing, so we read every line until EOF and
+    // split the digest share off from the ordinary value shares.
+    let mut value_shares: Vec<slip39::Share> = Vec::new();
+    let mut digest_share: Option<slip39::Share> = None;
+    let stdin = io::stdin();
+    let mut buf = String::new();
+    loop {
+        buf.clear();
+        if stdin.read_line(&mut buf)? == 0 {
+            break;
+        }
+        if buf.trim().is_empty() {
+            continue;
+        }
+        let share = slip39::Share::from_mnemonic(buf.trim())?;
+        if share.index == slip39::DIGEST_INDEX {
+            digest_share = Some(share);
+        } else {
+            value_shares.push(share);
+        }
+    }
+    // Every share of one secret carries the same identifier; a mismatch means
+    // shares from different backups have been mixed together.
+    let identifier = value_shares
+        .first()
+        .or(digest_share.as_ref())
+        .map(|s| s.identifier);
+    if let Some(identifier) = identifier {
+        let mut all = value_shares.iter().chain(digest_share.as_ref());
+        if !all.all(|s| s.identifier == identifier) {
+            return Err(Box::new(MainError::Message(
+                "shares have mismatched identifiers".into(),
+            )));
+        }
+    }
+    if value_shares.len() < threshold as usize {
+        return Err(Box::new(MainError::Message(format!(
+            "need at least {} shares, only got {}",
+            threshold,
+            value_shares.len()
+        ))));
+    }
+    let shares: Vec<(lagrange::Index, SecretBytes)> = value_shares
+        .iter()
+        .map(|s| Ok(((s.index - 1).into(), SecretBytes::from_slice(&s.value)?)))
+        .collect::<Result<Vec<_>, MainError>>()?;
+    let secret = reconstruct_secret(threshold, &shares)?;
+    match digest_share {
+        Some(digest_share) => {
+            if !slip39::verify_digest(&digest_share.value, &secret) {
+                return Err(Box::new(MainError::Message(
+                    "digest share does not match the reconstructed secret".into(),
+                )));
+            }
+        }
+        None if value_shares.len() == threshold as usize => {
+            eprintln!(
+                "warning: no digest share and no extra shares, the result could not be verified"
+            );
+        }
+        None => {}
     }
+    emit_reconstructed(&secret, output_file)
 }
 
 fn main() {
     let res = match Opt::from_args() {
         Opt::Random => random(),
-        Opt::Split { threshold, count } => split(threshold, count),
-        Opt::Combine { threshold } => combine(threshold),
+        Opt::Keygen => keygen(),
+        Opt::Split {
+            threshold,
+            count,
+            format,
+            recipients,
+            input_file,
+        } => split(threshold, count, format, recipients, input_file),
+        Opt::Combine {
+            threshold,
+            format,
+            secret_keys,
+            output_file,
+        } => combine(threshold, format, secret_keys, output_file),
     };
     if let Err(e) = res {
         println!("error: {}", e);
@@ -1,12 +1,14 @@
 use rand_core::RngCore;
 use std::ops;
-use subtle::{Choice, ConditionallySelectable};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// Represents some kind of field.
 ///
 /// We require addition and multiplication, along with inversion.
 ///
-/// We require copy mainly for convenience.
+/// We require copy mainly for convenience. We also require `ConstantTimeEq`,
+/// so that callers always have a non-leaking way to compare elements, rather
+/// than reaching for a test-only `PartialEq`.
 pub trait Field:
     Copy
     + ops::Add<Output = Self>
@@ -17,8 +19,12 @@ pub trait Field:
     + ops::Mul<Output = Self>
     + ops::MulAssign
     + From<u64>
+    + ConstantTimeEq
 {
     /// Return the multiplicative inverse of this element.
+    ///
+    /// This maps `0` to `0` rather than signaling an error; use `invert`
+    /// when the caller needs to branchlessly detect that case.
     fn inverse(self) -> Self;
     /// Return the multlicative unit in this field.
     fn one() -> Self;
@@ -26,16 +32,105 @@ pub trait Field:
     fn zero() -> Self;
     /// Create a random element of this field.
     fn random<R: RngCore>(rng: &mut R) -> Self;
+    /// Square this element.
+    ///
+    /// In a binary field squaring is a linear bit-spread, much cheaper than a
+    /// general multiply; `inverse` leans on this for its many Frobenius steps.
+    fn square(self) -> Self;
+    /// Raise this element to a power by square-and-multiply.
+    ///
+    /// The exponent is assumed public, so branching on its bits is fine.
+    fn pow(self, mut exp: u64) -> Self {
+        let mut acc = Self::one();
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base;
+            }
+            base = base.square();
+            exp >>= 1;
+        }
+        acc
+    }
+    /// Invert this element, reporting the zero element as absent in constant time.
+    ///
+    /// `inverse` has no representable error value for `0`, so this wraps it in
+    /// a `CtOption` whose validity is derived from `ct_eq` rather than a branch.
+    fn invert(self) -> CtOption<Self> {
+        let is_zero = self.ct_eq(&Self::zero());
+        CtOption::new(self.inverse(), !is_zero)
+    }
+}
+
+/// Spread the 64 bits of `x` across a 128-bit value, a zero between each one.
+///
+/// This is the squaring map for a binary polynomial: `(sum a_i z^i)^2 =
+/// sum a_i z^(2i)`, so interleaving zeros is all a square needs before the usual
+/// reduction.
+fn spread(x: u64) -> u128 {
+    let mut x = u128::from(x);
+    x = (x | (x << 32)) & 0x0000_0000_ffff_ffff_0000_0000_ffff_ffff;
+    x = (x | (x << 16)) & 0x0000_ffff_0000_ffff_0000_ffff_0000_ffff;
+    x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff_00ff_00ff_00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333_3333_3333_3333_3333;
+    x = (x | (x << 1)) & 0x5555_5555_5555_5555_5555_5555_5555_5555;
+    x
 }
 
-// This function is useful to do inversion in a field of size 2^count.
-fn exp_two_count_minus_two<M: Copy + ops::MulAssign>(count: usize, mut acc: M, x: M) -> M {
-    for _ in 0..(count - 1) {
-        acc *= acc;
-        acc *= x;
+/// Square a binary polynomial, returning the wide `(hi, lo)` product.
+///
+/// Each limb spreads to two limbs of the result, so limb `i` lands at result
+/// limbs `2i` and `2i + 1`; the low `N` limbs become `lo` and the high `N`
+/// become `hi`, ready for a field's `reduce`.
+fn square_wide<const N: usize>(a: &BPoly<N>) -> (BPoly<N>, BPoly<N>) {
+    let mut lo = BPoly::<N>::zero();
+    let mut hi = BPoly::<N>::zero();
+    for i in 0..N {
+        let s = spread(a[i]);
+        for (pos, limb) in [(2 * i, s as u64), (2 * i + 1, (s >> 64) as u64)] {
+            if pos < N {
+                lo[pos] = limb;
+            } else {
+                hi[pos - N] = limb;
+            }
+        }
     }
-    acc *= acc;
-    acc
+    (hi, lo)
+}
+
+/// Invert `a` in GF(2^m) via the Itoh–Tsujii algorithm: `a^-1 = (a^(2^(m-1)-1))^2`.
+///
+/// We maintain `beta = a^(2^k - 1)`, starting from `a^(2^1 - 1) = a`, and walk
+/// the bits of `m - 1`: each step doubles the index with a Frobenius (`k`
+/// squarings) and a multiply, and each set bit appends one more with a squaring
+/// and a multiply. This spends only about `2*log2(m - 1)` multiplications rather
+/// than the `m - 1` a naive square-and-multiply would. The chain depends only on
+/// `m`, a per-field constant, so the trace is independent of `a` (and `a = 0`
+/// still maps to 0), keeping inversion — and every share and height flowing
+/// through it — constant time.
+fn itoh_tsujii_inverse<F: Field>(a: F, m: usize) -> F {
+    let e = m - 1;
+    let top = (usize::BITS - 1 - e.leading_zeros()) as usize;
+    // beta = a^(2^k - 1), starting from a^(2^1 - 1) = a.
+    let mut beta = a;
+    let mut k = 1usize;
+    for i in (0..top).rev() {
+        // Double the index: beta = (beta)^(2^k) * beta, taking k to 2k.
+        let mut frob = beta;
+        for _ in 0..k {
+            frob = frob.square();
+        }
+        beta = frob * beta;
+        k *= 2;
+        // Append bit `i` of `e`: beta = (beta)^2 * a, taking k to k + 1.
+        if (e >> i) & 1 == 1 {
+            beta = beta.square() * a;
+            k += 1;
+        }
+    }
+    debug_assert_eq!(k, e);
+    beta.square()
 }
 
 /// Represents a binary polynomial with 64 * N coefficients.
@@ -43,8 +138,8 @@ fn exp_two_count_minus_two<M: Copy + ops::MulAssign>(count: usize, mut acc: M, x
 /// This is useful as an intermediate building block towards building binary
 /// fields, which use polynomials for their arithmetic.
 #[derive(Clone, Copy, Debug)]
-// Only implement equality for tests. This is to avoid the temptation to introduce
-// a timing leak through equality comparison.
+// Only implement PartialEq for tests; ConstantTimeEq below is the real,
+// non-leaking comparison for production code.
 #[cfg_attr(test, derive(PartialEq))]
 struct BPoly<const N: usize> {
     data: [u64; N],
@@ -74,6 +169,16 @@ impl<const N: usize> ConditionallySelectable for BPoly<N> {
     }
 }
 
+impl<const N: usize> ConstantTimeEq for BPoly<N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let mut acc = Choice::from(1u8);
+        for i in 0..N {
+            acc &= self.data[i].ct_eq(&other.data[i]);
+        }
+        acc
+    }
+}
+
 impl<const N: usize> BPoly<N> {
     fn zero() -> Self {
         Self { data: [0; N] }
@@ -150,31 +255,249 @@ impl<const N: usize> ops::Mul for BPoly<N> {
     }
 }
 
-/// Represents the binary field GF(2^128).
+/// Hardware carryless multiplication, used when the CPU advertises it.
+///
+/// The schoolbook `BPoly::mul` stays as the portable, constant-time fallback;
+/// this module only provides the 128- and 256-bit wide products feeding the
+/// unchanged `reduce` routines. Every path is branch-free on the operand bits,
+/// so constant-time behaviour is preserved. Feature detection is a one-time
+/// property of the host CPU, not of any secret, so branching on it is fine.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+mod clmul {
+    use super::BPoly;
+
+    /// Wide 256-bit `(hi, lo)` product of two 128-bit values, or `None` when the
+    /// carryless-multiply instructions are unavailable.
+    pub(super) fn gf128_mul(a: BPoly<2>, b: BPoly<2>) -> Option<(BPoly<2>, BPoly<2>)> {
+        if !detected() {
+            return None;
+        }
+        let x = u128::from(a[0]) | (u128::from(a[1]) << 64);
+        let y = u128::from(b[0]) | (u128::from(b[1]) << 64);
+        // Safety: `detected()` confirmed the feature is present on this CPU.
+        let (hi, lo) = unsafe { clmul128(x, y) };
+        Some((bpoly2(hi), bpoly2(lo)))
+    }
+
+    /// Wide 512-bit `(hi, lo)` product of two 256-bit values, or `None` when the
+    /// carryless-multiply instructions are unavailable.
+    pub(super) fn gf256_mul(a: BPoly<4>, b: BPoly<4>) -> Option<(BPoly<4>, BPoly<4>)> {
+        if !detected() {
+            return None;
+        }
+        let a0 = u128::from(a[0]) | (u128::from(a[1]) << 64);
+        let a1 = u128::from(a[2]) | (u128::from(a[3]) << 64);
+        let b0 = u128::from(b[0]) | (u128::from(b[1]) << 64);
+        let b1 = u128::from(b[2]) | (u128::from(b[3]) << 64);
+        // Safety: `detected()` confirmed the feature is present on this CPU.
+        let (z0h, z0l) = unsafe { clmul128(a0, b0) };
+        let (z2h, z2l) = unsafe { clmul128(a1, b1) };
+        let (mh, ml) = unsafe { clmul128(a0 ^ a1, b0 ^ b1) };
+        // Karatsuba middle term: (a0+a1)(b0+b1) - a0 b0 - a1 b1, every "-" an XOR.
+        let (z1h, z1l) = (mh ^ z0h ^ z2h, ml ^ z0l ^ z2l);
+        // Assemble the 512-bit product z0 + z1 * x^128 + z2 * x^256.
+        let r0 = z0l;
+        let r1 = z0h ^ z1l;
+        let r2 = z1h ^ z2l;
+        let r3 = z2h;
+        Some((bpoly4(r2, r3), bpoly4(r0, r1)))
+    }
+
+    fn bpoly2(x: u128) -> BPoly<2> {
+        BPoly {
+            data: [x as u64, (x >> 64) as u64],
+        }
+    }
+
+    fn bpoly4(lo: u128, hi: u128) -> BPoly<4> {
+        BPoly {
+            data: [lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64],
+        }
+    }
+
+    /// A 128-bit by 128-bit carryless multiply via three `clmul64` calls
+    /// (Karatsuba), returning the 256-bit product as a `(hi, lo)` pair.
+    ///
+    /// Carrying the target feature here lets the `clmul64` intrinsics inline into
+    /// one feature boundary per wide product rather than one per sub-product.
+    #[inline]
+    #[cfg_attr(target_arch = "x86_64", target_feature(enable = "pclmulqdq"))]
+    #[cfg_attr(target_arch = "aarch64", target_feature(enable = "neon,aes"))]
+    unsafe fn clmul128(a: u128, b: u128) -> (u128, u128) {
+        let a0 = a as u64;
+        let a1 = (a >> 64) as u64;
+        let b0 = b as u64;
+        let b1 = (b >> 64) as u64;
+        let z0 = clmul64(a0, b0);
+        let z2 = clmul64(a1, b1);
+        let z1 = clmul64(a0 ^ a1, b0 ^ b1) ^ z0 ^ z2;
+        let lo = z0 ^ (z1 << 64);
+        let hi = z2 ^ (z1 >> 64);
+        (hi, lo)
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn detected() -> bool {
+        std::is_x86_feature_detected!("pclmulqdq")
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[inline]
+    #[target_feature(enable = "pclmulqdq")]
+    unsafe fn clmul64(a: u64, b: u64) -> u128 {
+        use core::arch::x86_64::*;
+        let x = _mm_set_epi64x(0, a as i64);
+        let y = _mm_set_epi64x(0, b as i64);
+        let z = _mm_clmulepi64_si128::<0x00>(x, y);
+        let lo = _mm_cvtsi128_si64(z) as u64;
+        let hi = _mm_cvtsi128_si64(_mm_srli_si128::<8>(z)) as u64;
+        u128::from(lo) | (u128::from(hi) << 64)
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn detected() -> bool {
+        std::arch::is_aarch64_feature_detected!("aes")
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[inline]
+    #[target_feature(enable = "neon,aes")]
+    unsafe fn clmul64(a: u64, b: u64) -> u128 {
+        core::arch::aarch64::vmull_p64(a, b)
+    }
+}
+
+/// The low-degree terms of a binary field's reduction polynomial.
+///
+/// `BinaryField<N, R>` represents the leading `z^(64*N)` term implicitly;
+/// `R::EXPONENTS` lists every other nonzero term, highest first, including the
+/// constant `z^0` term when the polynomial has one. Supplying these is the
+/// only arithmetic that distinguishes one field size from another, so a new
+/// one (GF(2^64), GF(2^512), ...) is a marker type and an `EXPONENTS` list,
+/// plus a `WideMul` impl for its `BPoly<N>` (see below).
+pub trait Reduction: Copy {
+    /// Exponents of the reduction polynomial's lower-degree terms.
+    const EXPONENTS: &'static [u32];
+}
+
+/// Reduction polynomial for GF(2^128): `z^128 + z^7 + z^2 + z + 1`.
 #[derive(Clone, Copy, Debug)]
-// Only implement equality for tests. This is to avoid the temptation to introduce
-// a timing leak through equality comparison.
 #[cfg_attr(test, derive(PartialEq))]
-pub struct GF128(BPoly<2>);
+pub struct Gf128Reduction;
 
-impl GF128 {
-    fn reduce((hi, mut lo): (BPoly<2>, BPoly<2>)) -> Self {
-        // The irreducible polynomial is z^128 + z^7 + z^2 + z + 1
-        for i in 0..2 {
-            lo[i] ^= (hi[i] << 7) ^ (hi[i] << 2) ^ (hi[i] << 1) ^ hi[i];
+impl Reduction for Gf128Reduction {
+    const EXPONENTS: &'static [u32] = &[7, 2, 1, 0];
+}
+
+/// Reduction polynomial for GF(2^256): `z^256 + z^10 + z^5 + z^2 + 1`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Gf256Reduction;
+
+impl Reduction for Gf256Reduction {
+    const EXPONENTS: &'static [u32] = &[10, 5, 2, 0];
+}
+
+/// Hooks a limb count into the hardware carryless-multiply path, when one exists.
+///
+/// `BinaryField` only implements `Mul` and `Field` for a limb count whose
+/// `BPoly<N>` implements this trait, as `BPoly<2>` and `BPoly<4>` do below for
+/// GF128 and GF256. An impl whose `clmul` returns `None` uses the portable
+/// `BPoly::mul` fallback.
+trait WideMul: Sized {
+    /// Attempt the hardware carryless-multiply path, returning `None` when
+    /// this limb count has no accelerated implementation, or the CPU lacks it.
+    fn clmul(a: Self, b: Self) -> Option<(Self, Self)>;
+}
+
+impl WideMul for BPoly<2> {
+    fn clmul(a: Self, b: Self) -> Option<(Self, Self)> {
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        {
+            clmul::gf128_mul(a, b)
+        }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        {
+            let _ = (a, b);
+            None
+        }
+    }
+}
+
+impl WideMul for BPoly<4> {
+    fn clmul(a: Self, b: Self) -> Option<(Self, Self)> {
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        {
+            clmul::gf256_mul(a, b)
+        }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        {
+            let _ = (a, b);
+            None
+        }
+    }
+}
+
+/// Represents the binary field `GF(2^(64*N))`, reduced modulo the polynomial `R`.
+///
+/// `GF128` and `GF256` used to be separate, hand-written types whose `ops`
+/// impls, conversions, and `reduce` differed only in their modulus exponents.
+/// Parameterizing the limb count `N` and the reduction polynomial `R` lets
+/// them share one implementation; a new field size needs a fresh marker type
+/// naming its irreducible polynomial's low-degree terms, and a `WideMul` impl
+/// for its `BPoly<N>` (which may return `None` to skip hardware acceleration).
+#[derive(Clone, Copy, Debug)]
+// Only implement PartialEq for tests; ConstantTimeEq below is the real,
+// non-leaking comparison for production code.
+#[cfg_attr(test, derive(PartialEq))]
+pub struct BinaryField<const N: usize, R>(BPoly<N>, std::marker::PhantomData<R>);
+
+impl<const N: usize, R: Reduction> BinaryField<N, R> {
+    fn from_poly(poly: BPoly<N>) -> Self {
+        Self(poly, std::marker::PhantomData)
+    }
+
+    /// Fold a wide `(hi, lo)` product down to `N` limbs using `R::EXPONENTS`.
+    ///
+    /// This is the generic version of the reduction the two hand-written
+    /// fields used to each spell out: every exponent folds `hi` into `lo` at
+    /// its own limb, carrying across the limb boundary (the constant `z^0`
+    /// term never carries, since that would mean shifting by 64). The result
+    /// of that fold has at most `max(EXPONENTS)` bits above limb `N - 1`, far
+    /// short of a second reduction, so folding it in once more finishes the job.
+    fn reduce((hi, mut lo): (BPoly<N>, BPoly<N>)) -> Self {
+        for i in 0..N {
+            let mut fold = 0u64;
+            for &e in R::EXPONENTS {
+                fold ^= hi[i] << e;
+            }
+            lo[i] ^= fold;
             if i > 0 {
-                lo[i] ^=
-                    (hi[i - 1] >> (64 - 7)) ^ (hi[i - 1] >> (64 - 2)) ^ (hi[i - 1] >> (64 - 1));
+                let mut carry = 0u64;
+                for &e in R::EXPONENTS {
+                    if e > 0 {
+                        carry ^= hi[i - 1] >> (64 - e);
+                    }
+                }
+                lo[i] ^= carry;
             }
         }
-        // The top value has at most 7 set bits, so we can safely include it as usual
-        let top = (hi[1] >> (64 - 7)) ^ (hi[1] >> (64 - 2)) ^ (hi[1] >> (64 - 1));
-        lo[0] ^= (top << 7) ^ (top << 2) ^ (top << 1) ^ top;
-        GF128(lo)
+        let mut top = 0u64;
+        for &e in R::EXPONENTS {
+            if e > 0 {
+                top ^= hi[N - 1] >> (64 - e);
+            }
+        }
+        let mut fold_top = 0u64;
+        for &e in R::EXPONENTS {
+            fold_top ^= top << e;
+        }
+        lo[0] ^= fold_top;
+        Self::from_poly(lo)
     }
 }
 
-impl ops::Add for GF128 {
+impl<const N: usize, R: Reduction> ops::Add for BinaryField<N, R> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -184,13 +507,13 @@ impl ops::Add for GF128 {
     }
 }
 
-impl ops::AddAssign for GF128 {
+impl<const N: usize, R: Reduction> ops::AddAssign for BinaryField<N, R> {
     fn add_assign(&mut self, rhs: Self) {
         self.0 += rhs.0;
     }
 }
 
-impl ops::Neg for GF128 {
+impl<const N: usize, R: Reduction> ops::Neg for BinaryField<N, R> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -198,7 +521,7 @@ impl ops::Neg for GF128 {
     }
 }
 
-impl ops::Sub for GF128 {
+impl<const N: usize, R: Reduction> ops::Sub for BinaryField<N, R> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -206,52 +529,81 @@ impl ops::Sub for GF128 {
     }
 }
 
-impl ops::SubAssign for GF128 {
+impl<const N: usize, R: Reduction> ops::SubAssign for BinaryField<N, R> {
     fn sub_assign(&mut self, rhs: Self) {
         *self += -rhs;
     }
 }
 
-impl ops::Mul for GF128 {
+impl<const N: usize, R: Reduction> ops::Mul for BinaryField<N, R>
+where
+    BPoly<N>: WideMul,
+{
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        Self::reduce(self.0 * rhs.0)
+        match WideMul::clmul(self.0, rhs.0) {
+            Some(product) => Self::reduce(product),
+            None => Self::reduce(self.0 * rhs.0),
+        }
     }
 }
 
-impl ops::MulAssign for GF128 {
+impl<const N: usize, R: Reduction> ops::MulAssign for BinaryField<N, R>
+where
+    BPoly<N>: WideMul,
+{
     fn mul_assign(&mut self, rhs: Self) {
         *self = *self * rhs;
     }
 }
 
-impl From<u64> for GF128 {
+impl<const N: usize, R: Reduction> From<u64> for BinaryField<N, R> {
     fn from(x: u64) -> Self {
-        Self(BPoly { data: [x, 0] })
+        let mut data = [0u64; N];
+        data[0] = x;
+        Self::from_poly(BPoly { data })
     }
 }
 
-impl Field for GF128 {
+impl<const N: usize, R: Reduction> Field for BinaryField<N, R>
+where
+    BPoly<N>: WideMul,
+{
     fn inverse(self) -> Self {
-        exp_two_count_minus_two(128, Self::one(), self)
+        itoh_tsujii_inverse(self, 64 * N)
     }
 
     fn one() -> Self {
-        Self(BPoly::one())
+        Self::from_poly(BPoly::one())
     }
 
     fn zero() -> Self {
-        Self(BPoly::zero())
+        Self::from_poly(BPoly::zero())
     }
 
-    fn random<R: RngCore>(rng: &mut R) -> Self {
-        let mut buf = [0; 16];
-        rng.fill_bytes(&mut buf);
-        Self::from(buf)
+    fn random<Rng: RngCore>(rng: &mut Rng) -> Self {
+        let mut data = [0u64; N];
+        for limb in data.iter_mut() {
+            *limb = rng.next_u64();
+        }
+        Self::from_poly(BPoly { data })
+    }
+
+    fn square(self) -> Self {
+        Self::reduce(square_wide(&self.0))
     }
 }
 
+impl<const N: usize, R: Reduction> ConstantTimeEq for BinaryField<N, R> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+/// Represents the binary field GF(2^128).
+pub type GF128 = BinaryField<2, Gf128Reduction>;
+
 impl Into<[u8; 16]> for GF128 {
     fn into(self) -> [u8; 16] {
         let mut out = [0; 16];
@@ -264,39 +616,49 @@ impl Into<[u8; 16]> for GF128 {
 
 impl From<[u8; 16]> for GF128 {
     fn from(data: [u8; 16]) -> Self {
-        let mut out = Self::zero();
+        let mut poly = BPoly::zero();
         for (i, chunk) in data.chunks_exact(8).enumerate() {
-            out.0[i] = u64::from_le_bytes(chunk.try_into().unwrap());
+            poly[i] = u64::from_le_bytes(chunk.try_into().unwrap());
         }
-        out
+        Self::from_poly(poly)
     }
 }
 
 /// Represents the binary field GF(2^256).
-#[derive(Clone, Copy, Debug)]
-// Only implement equality for tests. This is to avoid the temptation to introduce
-// a timing leak through equality comparison.
-#[cfg_attr(test, derive(PartialEq))]
-pub struct GF256(BPoly<4>);
+pub type GF256 = BinaryField<4, Gf256Reduction>;
 
-impl GF256 {
-    fn reduce((hi, mut lo): (BPoly<4>, BPoly<4>)) -> Self {
-        // The irreducible polynomial is z^256 + z^10 + z^5 + z^2 + 1
+impl Into<[u8; 32]> for GF256 {
+    fn into(self) -> [u8; 32] {
+        let mut out = [0; 32];
         for i in 0..4 {
-            lo[i] ^= (hi[i] << 10) ^ (hi[i] << 5) ^ (hi[i] << 2) ^ hi[i];
-            if i > 0 {
-                lo[i] ^=
-                    (hi[i - 1] >> (64 - 10)) ^ (hi[i - 1] >> (64 - 5)) ^ (hi[i - 1] >> (64 - 2));
-            }
+            out[8 * i..8 * (i + 1)].copy_from_slice(&self.0[i].to_le_bytes())
+        }
+        out
+    }
+}
+
+impl From<[u8; 32]> for GF256 {
+    fn from(data: [u8; 32]) -> Self {
+        let mut poly = BPoly::zero();
+        for (i, chunk) in data.chunks_exact(8).enumerate() {
+            poly[i] = u64::from_le_bytes(chunk.try_into().unwrap());
         }
-        // The top value has at most 10 set bits, so we can safely include it as usual
-        let top = (hi[3] >> (64 - 10)) ^ (hi[3] >> (64 - 5)) ^ (hi[3] >> (64 - 2));
-        lo[0] ^= (top << 10) ^ (top << 5) ^ (top << 2) ^ top;
-        GF256(lo)
+        Self::from_poly(poly)
     }
 }
 
-impl ops::Add for GF256 {
+/// Represents the binary field GF(2^8) = GF(2)[x] / (x^8 + x^4 + x^3 + x + 1).
+///
+/// Unlike `GF128`/`GF256`, a single element fits in a byte, so the element-wise
+/// sharing pipeline can share a secret one byte at a time without padding it up
+/// to a 16- or 32-byte block.
+#[derive(Clone, Copy, Debug)]
+// Only implement PartialEq for tests; ConstantTimeEq below is the real,
+// non-leaking comparison for production code.
+#[cfg_attr(test, derive(PartialEq))]
+pub struct GF8(u8);
+
+impl ops::Add for GF8 {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -306,13 +668,13 @@ impl ops::Add for GF256 {
     }
 }
 
-impl ops::AddAssign for GF256 {
+impl ops::AddAssign for GF8 {
     fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0;
+        self.0 ^= rhs.0;
     }
 }
 
-impl ops::Neg for GF256 {
+impl ops::Neg for GF8 {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -320,7 +682,7 @@ impl ops::Neg for GF256 {
     }
 }
 
-impl ops::Sub for GF256 {
+impl ops::Sub for GF8 {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -328,69 +690,87 @@ impl ops::Sub for GF256 {
     }
 }
 
-impl ops::SubAssign for GF256 {
+impl ops::SubAssign for GF8 {
     fn sub_assign(&mut self, rhs: Self) {
         *self += -rhs;
     }
 }
 
-impl ops::Mul for GF256 {
+impl ops::Mul for GF8 {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        Self::reduce(self.0 * rhs.0)
+        // Carryless multiply into a u16, selecting each shifted copy in constant
+        // time, mirroring `BPoly::mul`.
+        let a = u16::from(self.0);
+        let mut acc = 0u16;
+        for k in 0..8 {
+            let bit = Choice::from((rhs.0 >> k) & 1);
+            acc ^= u16::conditional_select(&0, &(a << k), bit);
+        }
+        // Reduce modulo x^8 + x^4 + x^3 + x + 1 (0x11b), highest bit first.
+        for k in (8..16).rev() {
+            let bit = Choice::from(((acc >> k) & 1) as u8);
+            let reduced = acc ^ (0x11b << (k - 8));
+            acc = u16::conditional_select(&acc, &reduced, bit);
+        }
+        GF8(acc as u8)
     }
 }
 
-impl ops::MulAssign for GF256 {
+impl ops::MulAssign for GF8 {
     fn mul_assign(&mut self, rhs: Self) {
         *self = *self * rhs;
     }
 }
 
-impl From<u64> for GF256 {
+impl From<u64> for GF8 {
     fn from(x: u64) -> Self {
-        Self(BPoly { data: [x, 0, 0, 0] })
+        Self(x as u8)
+    }
+}
+
+impl From<u8> for GF8 {
+    fn from(x: u8) -> Self {
+        Self(x)
     }
 }
 
-impl Field for GF256 {
+impl From<GF8> for u8 {
+    fn from(x: GF8) -> Self {
+        x.0
+    }
+}
+
+impl Field for GF8 {
     fn inverse(self) -> Self {
-        exp_two_count_minus_two(256, Self::one(), self)
+        itoh_tsujii_inverse(self, 8)
     }
 
     fn one() -> Self {
-        Self(BPoly::one())
+        Self(1)
     }
 
     fn zero() -> Self {
-        Self(BPoly::zero())
+        Self(0)
     }
 
     fn random<R: RngCore>(rng: &mut R) -> Self {
-        let mut buf = [0; 32];
+        let mut buf = [0u8; 1];
         rng.fill_bytes(&mut buf);
-        Self::from(buf)
+        Self(buf[0])
     }
-}
 
-impl Into<[u8; 32]> for GF256 {
-    fn into(self) -> [u8; 32] {
-        let mut out = [0; 32];
-        for i in 0..4 {
-            out[8 * i..8 * (i + 1)].copy_from_slice(&self.0[i].to_le_bytes())
-        }
-        out
+    fn square(self) -> Self {
+        // A byte multiply is already cheap and constant-time, so there is no
+        // bit-spread shortcut worth a second copy of the reduction.
+        self * self
     }
 }
 
-impl From<[u8; 32]> for GF256 {
-    fn from(data: [u8; 32]) -> Self {
-        let mut out = Self::zero();
-        for (i, chunk) in data.chunks_exact(8).enumerate() {
-            out.0[i] = u64::from_le_bytes(chunk.try_into().unwrap());
-        }
-        out
+impl ConstantTimeEq for GF8 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
     }
 }
 
@@ -399,6 +779,14 @@ mod test {
     use super::*;
     use proptest::prelude::*;
 
+    /// Compare two field elements in constant time.
+    ///
+    /// Used for the inverse round-trip assertions so that even the tests avoid a
+    /// data-dependent comparison of secret-like values.
+    fn ct_eq<F: Field>(a: F, b: F) -> bool {
+        bool::from(a.ct_eq(&b))
+    }
+
     // We can generate an arbitrary element just by choosing random bits
     prop_compose! {
         fn arb_bpoly()(data in any::<[u64;4]>()) -> BPoly<4> {
@@ -409,14 +797,21 @@ mod test {
     // We can generate an arbitrary element just by choosing random bits
     prop_compose! {
         fn arb_gf128()(data in any::<[u64;2]>()) -> GF128 {
-            GF128(BPoly { data })
+            GF128::from_poly(BPoly { data })
         }
     }
 
     // We can generate an arbitrary element just by choosing random bits
     prop_compose! {
         fn arb_gf256()(data in any::<[u64;4]>()) -> GF256 {
-            GF256(BPoly { data })
+            GF256::from_poly(BPoly { data })
+        }
+    }
+
+    // A byte is already a full GF(2^8) element, so any byte will do.
+    prop_compose! {
+        fn arb_gf8()(x in any::<u8>()) -> GF8 {
+            GF8(x)
         }
     }
 
@@ -481,8 +876,20 @@ mod test {
     proptest! {
         #[test]
         fn test_gf128_mul_inverse_is_one(a in arb_gf128()) {
-            if a != GF128::zero() {
-                assert_eq!(a * a.inverse(), GF128::one());
+            if !ct_eq(a, GF128::zero()) {
+                assert!(ct_eq(a * a.inverse(), GF128::one()));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_gf128_invert_matches_inverse_unless_zero(a in arb_gf128()) {
+            let inverted = a.invert();
+            if ct_eq(a, GF128::zero()) {
+                assert!(!bool::from(inverted.is_some()));
+            } else {
+                assert!(ct_eq(inverted.unwrap(), a.inverse()));
             }
         }
     }
@@ -511,8 +918,114 @@ mod test {
     proptest! {
         #[test]
         fn test_gf256_mul_inverse_is_one(a in arb_gf256()) {
-            if a != GF256::zero() {
-                assert_eq!(a * a.inverse(), GF256::one());
+            if !ct_eq(a, GF256::zero()) {
+                assert!(ct_eq(a * a.inverse(), GF256::one()));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_gf256_invert_matches_inverse_unless_zero(a in arb_gf256()) {
+            let inverted = a.invert();
+            if ct_eq(a, GF256::zero()) {
+                assert!(!bool::from(inverted.is_some()));
+            } else {
+                assert!(ct_eq(inverted.unwrap(), a.inverse()));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_gf128_square_is_self_mul(a in arb_gf128()) {
+            assert_eq!(a.square(), a * a);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_gf256_square_is_self_mul(a in arb_gf256()) {
+            assert_eq!(a.square(), a * a);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_gf8_multiplication_commutative(a in arb_gf8(), b in arb_gf8()) {
+            assert_eq!(a * b, b * a);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_gf8_multiplication_associative(a in arb_gf8(), b in arb_gf8(), c in arb_gf8()) {
+            assert_eq!(a * (b * c), (a * b) * c);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_gf8_mul_one_identity(a in arb_gf8()) {
+            assert_eq!(a * GF8::one(), a);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_gf8_mul_inverse_is_one(a in arb_gf8()) {
+            if !ct_eq(a, GF8::zero()) {
+                assert!(ct_eq(a * a.inverse(), GF8::one()));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_gf8_invert_matches_inverse_unless_zero(a in arb_gf8()) {
+            let inverted = a.invert();
+            if ct_eq(a, GF8::zero()) {
+                assert!(!bool::from(inverted.is_some()));
+            } else {
+                assert!(ct_eq(inverted.unwrap(), a.inverse()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_gf8_aes_example() {
+        // The worked example from the Rijndael/AES spec: in GF(2^8) modulo
+        // x^8 + x^4 + x^3 + x + 1, we have 0x57 * 0x83 = 0xc1.
+        assert_eq!(GF8::from(0x57u8) * GF8::from(0x83u8), GF8::from(0xc1u8));
+    }
+
+    proptest! {
+        #[test]
+        fn test_gf128_pow_matches_repeated_mul(a in arb_gf128(), e in 0u64..32) {
+            let mut expected = GF128::one();
+            for _ in 0..e {
+                expected = expected * a;
+            }
+            assert_eq!(a.pow(e), expected);
+        }
+    }
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    proptest! {
+        #[test]
+        fn test_gf128_clmul_matches_schoolbook(a in arb_gf128(), b in arb_gf128()) {
+            if let Some(product) = clmul::gf128_mul(a.0, b.0) {
+                assert_eq!(product, a.0 * b.0);
+            }
+        }
+    }
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    proptest! {
+        #[test]
+        fn test_gf256_clmul_matches_schoolbook(a in arb_gf256(), b in arb_gf256()) {
+            if let Some(product) = clmul::gf256_mul(a.0, b.0) {
+                assert_eq!(product, a.0 * b.0);
             }
         }
     }
@@ -533,9 +1046,9 @@ mod test {
 
     #[test]
     fn test_gf128_z127_times_z() {
-        let z127 = GF128(BPoly { data: [0, 1 << 63] });
-        let z = GF128(BPoly { data: [2, 0] });
-        let expected = GF128(BPoly {
+        let z127 = GF128::from_poly(BPoly { data: [0, 1 << 63] });
+        let z = GF128::from_poly(BPoly { data: [2, 0] });
+        let expected = GF128::from_poly(BPoly {
             data: [1 | (1 << 1) | (1 << 2) | (1 << 7), 0],
         });
         assert_eq!(z * z127, expected);
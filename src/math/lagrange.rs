@@ -70,6 +70,11 @@ impl Sharing {
         debug_assert!(threshold > 0 && threshold <= count);
         Self { threshold, count }
     }
+
+    /// The number of shares required to reconstruct the secret.
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
 }
 
 /// Split a secret into multiple shares.
@@ -91,6 +96,54 @@ pub fn split<F: field::Field, R: RngCore + CryptoRng>(
     acc
 }
 
+/// Split a byte string into shares, sharing each byte independently over GF(2^8).
+///
+/// Each byte gets its own degree `threshold - 1` polynomial, and every share is
+/// the concatenation of one evaluated byte per input byte. This imposes no
+/// length ceiling and needs no padding, unlike the block-at-a-time `split`.
+/// Every share is written directly into a locked `SecretBytes` buffer, so a
+/// share's bytes never exist as a bare, unprotected `Vec<u8>`.
+pub fn split_bytes<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    secret: &[u8],
+    sharing: Sharing,
+) -> Result<Vec<(Index, crate::secret::SecretBytes)>, crate::MainError> {
+    let mut shares: Vec<(Index, crate::secret::SecretBytes)> = (0..sharing.count)
+        .map(|i| Ok((Index(i), crate::secret::SecretBytes::zeroed(secret.len())?)))
+        .collect::<Result<Vec<_>, crate::MainError>>()?;
+    for (j, &byte) in secret.iter().enumerate() {
+        for (slot, (_, height)) in split(rng, field::GF8::from(byte), sharing).into_iter().enumerate()
+        {
+            shares[slot].1[j] = u8::from(height);
+        }
+    }
+    Ok(shares)
+}
+
+/// Reconstruct a byte string shared with `split_bytes`, verifying extra shares.
+///
+/// Each byte column is interpolated on its own with `reconstruct_checked`, so an
+/// inconsistent share is reported just as it is for the block-at-a-time path.
+/// The result is written directly into a locked `SecretBytes` buffer, so the
+/// reconstructed secret never exists as a bare, unprotected `Vec<u8>`.
+///
+/// The caller must supply at least one share, all of the same length.
+pub fn reconstruct_bytes_checked(
+    threshold: usize,
+    shares: &[(Index, crate::secret::SecretBytes)],
+) -> Result<crate::secret::SecretBytes, crate::MainError> {
+    let len = shares[0].1.len();
+    let mut out = crate::secret::SecretBytes::zeroed(len)?;
+    for (j, slot) in out.iter_mut().enumerate() {
+        let column: Vec<(Index, field::GF8)> = shares
+            .iter()
+            .map(|(i, value)| (*i, field::GF8::from(value[j])))
+            .collect();
+        *slot = u8::from(reconstruct_checked(threshold, &column)?);
+    }
+    Ok(out)
+}
+
 /// A convenience struct to hold the points we've evaluated the polynomial at.
 struct EvaluationPoints<F> {
     points: Vec<F>,
@@ -120,6 +173,41 @@ impl<F: field::Field> EvaluationPoints<F> {
         out
     }
 
+    /// Reconstruct every coefficient of the interpolating polynomial.
+    ///
+    /// Unlike `reconstruct_zero`, which only recovers the secret, this builds
+    /// the full polynomial in coefficient form so it can be re-evaluated at
+    /// other points to check additional shares.
+    fn reconstruct_all_coefficients(&self) -> Polynomial<F> {
+        let n = self.points.len();
+        let mut coefficients = vec![F::zero(); n];
+        for j in 0..n {
+            // Build the basis numerator prod_{i != j} (x - points[i]) in place,
+            // together with its denominator prod_{i != j} (points[j] - points[i]).
+            let mut basis = vec![F::zero(); n];
+            basis[0] = F::one();
+            let mut degree = 0;
+            let mut denominator = F::one();
+            for (i, &a_i) in self.points.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let neg = -a_i;
+                for k in (1..=degree + 1).rev() {
+                    basis[k] = basis[k - 1] + basis[k] * neg;
+                }
+                basis[0] = basis[0] * neg;
+                degree += 1;
+                denominator *= self.points[j] - a_i;
+            }
+            let scale = self.heights[j] * denominator.inverse();
+            for k in 0..=degree {
+                coefficients[k] += basis[k] * scale;
+            }
+        }
+        Polynomial { coefficients }
+    }
+
     fn from_shares(shares: &[(Index, F)]) -> Self {
         let points = shares.iter().map(|(i, _)| i.to_field()).collect();
         let heights = shares.iter().map(|(_, f)| *f).collect();
@@ -135,6 +223,42 @@ pub fn reconstruct<F: field::Field>(shares: &[(Index, F)]) -> F {
     EvaluationPoints::from_shares(shares).reconstruct_zero()
 }
 
+/// Reconstruct a secret, verifying any shares beyond the threshold.
+///
+/// The first `threshold` shares determine the polynomial; every remaining share
+/// is then checked by re-evaluating that polynomial at its index and comparing,
+/// in constant time, against the height the share claims. A disagreement means
+/// one of the shares is wrong, reported as the offending (1-based) index.
+///
+/// The caller must supply at least `threshold` shares.
+pub fn reconstruct_checked<F: field::Field>(
+    threshold: usize,
+    shares: &[(Index, F)],
+) -> Result<F, crate::MainError> {
+    // Interpolation is only well defined at distinct points, and a repeated
+    // index would otherwise divide by zero and silently corrupt the result.
+    for i in 0..shares.len() {
+        for j in (i + 1)..shares.len() {
+            if u8::from(shares[i].0) == u8::from(shares[j].0) {
+                return Err(crate::MainError::InconsistentShare {
+                    index: u8::from(shares[i].0) + 1,
+                });
+            }
+        }
+    }
+    let points = EvaluationPoints::from_shares(&shares[..threshold]);
+    let polynomial = points.reconstruct_all_coefficients();
+    for (index, height) in &shares[threshold..] {
+        let recomputed = polynomial.evaluate(index.to_field());
+        if !bool::from(recomputed.ct_eq(height)) {
+            return Err(crate::MainError::InconsistentShare {
+                index: u8::from(*index) + 1,
+            });
+        }
+    }
+    Ok(polynomial.coefficients[0])
+}
+
 #[cfg(test)]
 mod test {
     use crate::math::field::Field;
@@ -152,4 +276,27 @@ mod test {
         let reconstructed = reconstruct(&shares);
         assert_eq!(secret, reconstructed);
     }
+
+    #[test]
+    fn test_checked_reconstruction_accepts_extra_shares() {
+        let mut rng = &mut OsRng;
+        let secret = GF128::random(&mut rng);
+        let shares = split(&mut rng, secret, Sharing::new(3, 5));
+        let reconstructed = reconstruct_checked(3, &shares).unwrap();
+        assert_eq!(secret, reconstructed);
+    }
+
+    #[test]
+    fn test_checked_reconstruction_detects_bad_share() {
+        let mut rng = &mut OsRng;
+        let secret = GF128::random(&mut rng);
+        let mut shares = split(&mut rng, secret, Sharing::new(3, 5));
+        // Corrupt a share past the threshold so it disagrees with the others.
+        shares[4].1 += GF128::one();
+        let index = u8::from(shares[4].0) + 1;
+        match reconstruct_checked(3, &shares) {
+            Err(crate::MainError::InconsistentShare { index: bad }) => assert_eq!(bad, index),
+            other => panic!("expected an inconsistent share, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,248 @@
+//! Sealing shares to a recipient's X25519 public key.
+//!
+//! Instead of printing a share in the clear, `split` can encrypt each share to
+//! exactly one recipient: an ephemeral X25519 keypair is generated, a
+//! Diffie–Hellman shared secret is computed against the recipient's public key,
+//! a symmetric key is derived with HKDF-SHA256, and the share bytes are sealed
+//! with ChaCha20-Poly1305. The ephemeral public key travels alongside the
+//! ciphertext so the recipient can re-derive the same key on `combine`.
+
+use chacha20poly1305::aead::{Aead, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, KeyInit};
+use hkdf::Hkdf;
+use rand_core::OsRng;
+use sha2::Sha256;
+use std::error::Error;
+use x25519_dalek::{EphemeralSecret, PublicKey, StaticSecret};
+
+use crate::MainError;
+
+/// The context string mixed into the HKDF expansion.
+const HKDF_INFO: &[u8] = b"seed-split sealed share v1";
+
+/// A fixed all-zero nonce.
+///
+/// This is safe because every share is sealed under a fresh ephemeral key, so a
+/// `(key, nonce)` pair is never reused.
+const NONCE: [u8; 12] = [0u8; 12];
+
+/// Parse a recipient public key from a BIP39 mnemonic of 32 bytes.
+///
+/// The mnemonic must encode the public key half produced by
+/// [`generate_keypair`] (or an equivalent `PublicKey::from(&StaticSecret)`
+/// derivation). An arbitrary 32-byte mnemonic, such as a seed phrase or the
+/// raw bytes of a secret key, is a valid curve point but not the one paired
+/// with any particular secret key, so sealing to it leaves no secret key able
+/// to unseal the result.
+pub fn parse_public_key(mnemonic: &str) -> Result<PublicKey, Box<dyn Error>> {
+    Ok(PublicKey::from(parse_key_bytes(mnemonic)?))
+}
+
+/// Parse a recipient secret key from a BIP39 mnemonic of 32 bytes.
+///
+/// Pair this with the public key mnemonic emitted alongside it by
+/// [`generate_keypair`]; it is not meant to parse an arbitrary seed phrase.
+pub fn parse_secret_key(mnemonic: &str) -> Result<StaticSecret, Box<dyn Error>> {
+    Ok(StaticSecret::from(parse_key_bytes(mnemonic)?))
+}
+
+/// Generate a fresh X25519 keypair for sealing, returned as a pair of 32-byte
+/// mnemonics `(secret_key, public_key)`.
+///
+/// The public key is always derived with `PublicKey::from(&StaticSecret)`, so
+/// it is guaranteed to pair with the secret key mnemonic emitted alongside
+/// it; these are the only mnemonics `parse_public_key`/`parse_secret_key` are
+/// meant to accept.
+pub fn generate_keypair() -> Result<(String, String), Box<dyn Error>> {
+    let secret = StaticSecret::random_from_rng(OsRng);
+    let public = PublicKey::from(&secret);
+    let secret_mnemonic = bytes_to_words(&secret.to_bytes())?;
+    let public_mnemonic = bytes_to_words(public.as_bytes())?;
+    Ok((secret_mnemonic, public_mnemonic))
+}
+
+fn parse_key_bytes(mnemonic: &str) -> Result<[u8; 32], Box<dyn Error>> {
+    let parsed = bip39::Mnemonic::parse(mnemonic)?;
+    let (arr, len) = parsed.to_entropy_array();
+    if len != 32 {
+        return Err(Box::new(MainError::Message(format!(
+            "a key mnemonic must encode 32 bytes, got {}",
+            len
+        ))));
+    }
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&arr[..32]);
+    Ok(out)
+}
+
+/// Derive the symmetric key from a Diffie–Hellman shared secret.
+fn derive_key(shared: &[u8]) -> [u8; 32] {
+    let hk = Hkdf::<Sha256>::new(None, shared);
+    let mut okm = [0u8; 32];
+    hk.expand(HKDF_INFO, &mut okm)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    okm
+}
+
+/// Seal a single share, addressed to `recipient`, into mnemonic words.
+///
+/// The `index` is the share's member index; it travels with the ciphertext so
+/// that `combine` can feed the recovered share back into the Lagrange machinery.
+pub fn seal(recipient: &PublicKey, index: u8, share: &[u8]) -> Result<String, Box<dyn Error>> {
+    let ephemeral = EphemeralSecret::random_from_rng(OsRng);
+    let ephemeral_public = PublicKey::from(&ephemeral);
+    let shared = ephemeral.diffie_hellman(recipient);
+    let cipher = ChaCha20Poly1305::new_from_slice(&derive_key(shared.as_bytes()))
+        .expect("32 bytes is a valid ChaCha20-Poly1305 key");
+    // The cleartext header travels as associated data, so tampering with the
+    // index or length is caught by the AEAD tag on `combine`. The length is a
+    // big-endian u16, not a single byte, so it can't silently wrap for a
+    // share over 255 bytes.
+    let len: u16 = share.len().try_into().map_err(|_| {
+        MainError::Message(format!(
+            "share is {} bytes, too large to seal (max {})",
+            share.len(),
+            u16::MAX
+        ))
+    })?;
+    let len_bytes = len.to_be_bytes();
+    let header = [index, len_bytes[0], len_bytes[1]];
+    let ciphertext = cipher
+        .encrypt(
+            &NONCE.into(),
+            Payload {
+                msg: share,
+                aad: &header,
+            },
+        )
+        .map_err(|_| MainError::Message("failed to seal share".into()))?;
+    // blob = index || length (big-endian u16) || ephemeral public key || ciphertext+tag
+    let mut blob = Vec::with_capacity(header.len() + 32 + ciphertext.len());
+    blob.extend_from_slice(&header);
+    blob.extend_from_slice(ephemeral_public.as_bytes());
+    blob.extend_from_slice(&ciphertext);
+    bytes_to_words(&blob)
+}
+
+/// Attempt to unseal a share with `secret`.
+///
+/// Returns the recovered `(index, share_bytes)` on success, or `None` if the
+/// share was sealed to a different recipient (the AEAD tag fails to verify).
+pub fn unseal(
+    secret: &StaticSecret,
+    mnemonic: &str,
+) -> Result<Option<(u8, Vec<u8>)>, Box<dyn Error>> {
+    let blob = words_to_bytes(mnemonic)?;
+    if blob.len() < 3 + 32 {
+        return Err(Box::new(MainError::Message("sealed share is too short".into())));
+    }
+    let header = [blob[0], blob[1], blob[2]];
+    let index = blob[0];
+    if index == 0 {
+        return Err(Box::new(MainError::Message(
+            "sealed share has an invalid index of 0".into(),
+        )));
+    }
+    let len = usize::from(u16::from_be_bytes([blob[1], blob[2]]));
+    let ephemeral_public: [u8; 32] = blob[3..35]
+        .try_into()
+        .expect("slice of exactly 32 bytes");
+    let end = 35 + len + 16;
+    if blob.len() < end {
+        return Err(Box::new(MainError::Message("sealed share is truncated".into())));
+    }
+    let ciphertext = &blob[35..end];
+    let shared = secret.diffie_hellman(&PublicKey::from(ephemeral_public));
+    let cipher = ChaCha20Poly1305::new_from_slice(&derive_key(shared.as_bytes()))
+        .expect("32 bytes is a valid ChaCha20-Poly1305 key");
+    match cipher.decrypt(
+        &NONCE.into(),
+        Payload {
+            msg: ciphertext,
+            aad: &header,
+        },
+    ) {
+        Ok(share) => Ok(Some((index, share))),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Encode a byte blob as whitespace-joined BIP39 mnemonics.
+///
+/// The blob is zero-padded to a multiple of 32 bytes and split into 24-word
+/// chunks, so the word count alone tells `words_to_bytes` how to re-chunk it.
+fn bytes_to_words(blob: &[u8]) -> Result<String, Box<dyn Error>> {
+    let mut padded = blob.to_vec();
+    while padded.len() % 32 != 0 {
+        padded.push(0);
+    }
+    let mut words = Vec::new();
+    for chunk in padded.chunks(32) {
+        let arr: [u8; 32] = chunk.try_into().expect("chunk of exactly 32 bytes");
+        words.push(bip39::Mnemonic::from_entropy(&arr)?.to_string());
+    }
+    Ok(words.join(" "))
+}
+
+/// Decode a whitespace-joined sequence of 24-word BIP39 mnemonics into bytes.
+fn words_to_bytes(mnemonic: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    if words.is_empty() || words.len() % 24 != 0 {
+        return Err(Box::new(MainError::Message(
+            "a sealed share must be a whole number of 24-word mnemonics".into(),
+        )));
+    }
+    let mut out = Vec::with_capacity(words.len() / 24 * 32);
+    for chunk in words.chunks(24) {
+        let parsed = bip39::Mnemonic::parse(chunk.join(" "))?;
+        let (arr, len) = parsed.to_entropy_array();
+        out.extend_from_slice(&arr[..len]);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn keypair(seed: u8) -> (StaticSecret, PublicKey) {
+        let secret = StaticSecret::from([seed; 32]);
+        let public = PublicKey::from(&secret);
+        (secret, public)
+    }
+
+    #[test]
+    fn test_generate_keypair_is_its_own_matching_pair() {
+        let (secret_mnemonic, public_mnemonic) = generate_keypair().unwrap();
+        let secret = parse_secret_key(&secret_mnemonic).unwrap();
+        let public = parse_public_key(&public_mnemonic).unwrap();
+        assert_eq!(PublicKey::from(&secret).as_bytes(), public.as_bytes());
+    }
+
+    #[test]
+    fn test_seal_unseal_round_trips_a_share() {
+        let (secret, public) = keypair(7);
+        let share = b"a short share";
+        let sealed = seal(&public, 3, share).unwrap();
+        let (index, recovered) = unseal(&secret, &sealed).unwrap().unwrap();
+        assert_eq!(index, 3);
+        assert_eq!(recovered, share);
+    }
+
+    #[test]
+    fn test_seal_unseal_round_trips_a_share_over_255_bytes() {
+        let (secret, public) = keypair(9);
+        let share: Vec<u8> = (0..300).map(|i| (i % 256) as u8).collect();
+        let sealed = seal(&public, 1, &share).unwrap();
+        let (_, recovered) = unseal(&secret, &sealed).unwrap().unwrap();
+        assert_eq!(recovered, share);
+    }
+
+    #[test]
+    fn test_unseal_with_the_wrong_recipient_returns_none() {
+        let (_, public) = keypair(1);
+        let (wrong_secret, _) = keypair(2);
+        let sealed = seal(&public, 1, b"a share").unwrap();
+        assert!(unseal(&wrong_secret, &sealed).unwrap().is_none());
+    }
+}
@@ -0,0 +1,97 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{compiler_fence, Ordering};
+
+use crate::MainError;
+
+/// A buffer of secret bytes whose backing pages are locked into RAM.
+///
+/// The pages are `mlock`ed on construction so the kernel will not page the
+/// cleartext out to swap, and are zeroed and `munlock`ed on drop so that no
+/// secret material outlives the wrapper. This is used to hold the entropy and
+/// field elements that flow through `split`/`reconstruct`.
+pub struct SecretBytes {
+    data: Vec<u8>,
+}
+
+impl SecretBytes {
+    /// Allocate `len` locked bytes, all initially zero.
+    pub fn zeroed(len: usize) -> Result<Self, MainError> {
+        let data = vec![0u8; len];
+        lock(&data)?;
+        Ok(Self { data })
+    }
+
+    /// Copy a slice into a freshly locked buffer.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, MainError> {
+        let mut out = Self::zeroed(bytes.len())?;
+        out.data.copy_from_slice(bytes);
+        Ok(out)
+    }
+}
+
+impl Deref for SecretBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl DerefMut for SecretBytes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        zeroize(&mut self.data);
+        if let Err(e) = unlock(&self.data) {
+            eprintln!("warning: {}", e);
+        }
+    }
+}
+
+/// Overwrite `buf` with zeros using volatile writes, so the compiler can't
+/// elide the clearing of a buffer that is about to be dropped or go out of
+/// scope.
+///
+/// Use this to wipe a plain `Vec<u8>`/`[u8]` that briefly held secret material
+/// copied out of a `SecretBytes` (e.g. to build a type we don't own), since
+/// such a buffer is otherwise left unprotected and un-zeroized on drop.
+pub fn zeroize(buf: &mut [u8]) {
+    for b in buf.iter_mut() {
+        unsafe { std::ptr::write_volatile(b, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// `mlock` the pages backing a buffer, reporting failures as a `MainError`.
+fn lock(buf: &[u8]) -> Result<(), MainError> {
+    if buf.is_empty() {
+        return Ok(());
+    }
+    let ret = unsafe { libc::mlock(buf.as_ptr() as *const libc::c_void, buf.len()) };
+    if ret != 0 {
+        return Err(MainError::Mlock {
+            addr: buf.as_ptr() as usize,
+            len: buf.len(),
+        });
+    }
+    Ok(())
+}
+
+/// `munlock` the pages backing a buffer, reporting failures as a `MainError`.
+fn unlock(buf: &[u8]) -> Result<(), MainError> {
+    if buf.is_empty() {
+        return Ok(());
+    }
+    let ret = unsafe { libc::munlock(buf.as_ptr() as *const libc::c_void, buf.len()) };
+    if ret != 0 {
+        return Err(MainError::Munlock {
+            addr: buf.as_ptr() as usize,
+            len: buf.len(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,302 @@
+//! A SLIP-0039 compatible share encoding.
+//!
+//! Instead of a bare BIP39 mnemonic prefixed by a decimal index, a share is
+//! packed into a self-describing bit layout (identifier, threshold, member
+//! index, and the share value) encoded with the 1024-word SLIP-0039 wordlist
+//! and protected by a two-word Reed–Solomon checksum over GF(1024). A separate
+//! "digest share" lets `combine` confirm the reconstructed secret before
+//! printing it.
+
+mod wordlist;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+use crate::MainError;
+
+use self::wordlist::{index_of, RADIX, WORDLIST};
+
+/// The member index reserved for the digest share.
+pub const DIGEST_INDEX: u8 = 0;
+
+/// The largest member index (and threshold) representable in the 5-bit fields.
+pub const MAX_INDEX: u8 = (1 << INDEX_BITS) - 1;
+
+/// A single decoded SLIP-0039 share.
+#[derive(Clone, Debug)]
+pub struct Share {
+    /// A random identifier shared by every share of one secret.
+    pub identifier: u16,
+    /// The number of shares required to reconstruct the secret.
+    pub threshold: u8,
+    /// The member index of this share.
+    pub index: u8,
+    /// The raw share value.
+    pub value: Vec<u8>,
+}
+
+// Field widths, in bits, of the packed header preceding the value.
+const ID_BITS: usize = 15;
+const THRESHOLD_BITS: usize = 5;
+const INDEX_BITS: usize = 5;
+// Wide enough that a share value's length (up to 65535 bytes) never wraps;
+// an 8-bit field silently truncated and corrupted any share over 255 bytes.
+const LEN_BITS: usize = 16;
+const HEADER_BITS: usize = ID_BITS + THRESHOLD_BITS + INDEX_BITS + LEN_BITS;
+
+impl Share {
+    /// Encode this share as a space-separated SLIP-0039 mnemonic.
+    pub fn to_mnemonic(&self) -> String {
+        let mut writer = BitWriter::new();
+        writer.write(u64::from(self.identifier), ID_BITS);
+        writer.write(u64::from(self.threshold), THRESHOLD_BITS);
+        writer.write(u64::from(self.index), INDEX_BITS);
+        writer.write(self.value.len() as u64, LEN_BITS);
+        for &b in &self.value {
+            writer.write(u64::from(b), 8);
+        }
+        let mut words = writer.finish();
+        let [c1, c0] = rs_checksum(&words);
+        words.push(c1);
+        words.push(c0);
+        words
+            .iter()
+            .map(|&w| WORDLIST[w as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Decode a SLIP-0039 mnemonic, verifying its checksum.
+    pub fn from_mnemonic(s: &str) -> Result<Self, MainError> {
+        let mut words = Vec::new();
+        for word in s.split_whitespace() {
+            let idx = index_of(word)
+                .ok_or_else(|| MainError::Message(format!("unknown slip39 word: {}", word)))?;
+            words.push(idx);
+        }
+        if words.len() < 2 {
+            return Err(MainError::Message("slip39 share is too short".into()));
+        }
+        let (data, check) = words.split_at(words.len() - 2);
+        if rs_checksum(data) != [check[0], check[1]] {
+            return Err(MainError::Message("slip39 checksum mismatch".into()));
+        }
+        let mut reader = BitReader::new(data);
+        let identifier = reader.read(ID_BITS)? as u16;
+        let threshold = reader.read(THRESHOLD_BITS)? as u8;
+        let index = reader.read(INDEX_BITS)? as u8;
+        let len = reader.read(LEN_BITS)? as usize;
+        let mut value = Vec::with_capacity(len);
+        for _ in 0..len {
+            value.push(reader.read(8)? as u8);
+        }
+        // Any trailing bits are zero padding and must indeed be zero.
+        if !reader.rest_is_zero() {
+            return Err(MainError::Message("slip39 share has nonzero padding".into()));
+        }
+        Ok(Share {
+            identifier,
+            threshold,
+            index,
+            value,
+        })
+    }
+}
+
+/// Compute the digest of a secret under a random key, truncated to `len` bytes
+/// (at most the 32 bytes of an HMAC-SHA256 tag).
+///
+/// This is the payload of the digest share: `combine` recomputes it from the
+/// reconstructed secret and checks it for equality.
+pub fn digest(key: &[u8], secret: &[u8], len: usize) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(secret);
+    let tag = mac.finalize().into_bytes();
+    tag[..len.min(tag.len())].to_vec()
+}
+
+/// Check a digest share value against a reconstructed secret in constant time.
+///
+/// The digest share value is `key || expected_digest`, split in half.
+pub fn verify_digest(digest_value: &[u8], secret: &[u8]) -> bool {
+    let half = digest_value.len() / 2;
+    let (key, expected) = digest_value.split_at(half);
+    let actual = digest(key, secret, expected.len());
+    actual.ct_eq(expected).into()
+}
+
+/// A two-word Reed–Solomon checksum over GF(1024).
+///
+/// The checksum symbols are the remainder of `data(x) * x^2` modulo the
+/// generator `g(x) = (x - 1)(x - 2)`, so a single mistyped word is detected.
+fn rs_checksum(data: &[u16]) -> [u16; 2] {
+    // g(x) = x^2 + g1 x + g0 with g1 = 1 ^ 2 = 3 and g0 = 1 * 2 = 2.
+    const G1: u16 = 3;
+    const G0: u16 = 2;
+    let (mut r1, mut r0) = (0u16, 0u16);
+    for &v in data {
+        let fb = v ^ r1;
+        r1 = r0 ^ gf1024_mul(fb, G1);
+        r0 = gf1024_mul(fb, G0);
+    }
+    [r1, r0]
+}
+
+/// Multiply two elements of GF(1024) = GF(2)[x] / (x^10 + x^3 + 1).
+fn gf1024_mul(a: u16, b: u16) -> u16 {
+    // Carryless multiply followed by reduction, mirroring the binary-field
+    // multiply in `math::field`, but small enough to stay in a u32.
+    let mut acc = 0u32;
+    for k in 0..RADIX.trailing_zeros() {
+        if (b >> k) & 1 == 1 {
+            acc ^= u32::from(a) << k;
+        }
+    }
+    // Reduce modulo x^10 + x^3 + 1 (0x409).
+    for k in (10..=18).rev() {
+        if (acc >> k) & 1 == 1 {
+            acc ^= 0x409 << (k - 10);
+        }
+    }
+    acc as u16
+}
+
+/// Packs values of arbitrary bit width into a big-endian stream of 10-bit words.
+struct BitWriter {
+    words: Vec<u16>,
+    buffer: u32,
+    bits: usize,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            words: Vec::new(),
+            buffer: 0,
+            bits: 0,
+        }
+    }
+
+    fn write(&mut self, value: u64, width: usize) {
+        for i in (0..width).rev() {
+            self.buffer = (self.buffer << 1) | ((value >> i) as u32 & 1);
+            self.bits += 1;
+            if self.bits == 10 {
+                self.words.push(self.buffer as u16);
+                self.buffer = 0;
+                self.bits = 0;
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<u16> {
+        if self.bits > 0 {
+            self.buffer <<= 10 - self.bits;
+            self.words.push(self.buffer as u16);
+        }
+        self.words
+    }
+}
+
+/// Reads values of arbitrary bit width out of a big-endian stream of 10-bit words.
+struct BitReader<'a> {
+    words: &'a [u16],
+    bit: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(words: &'a [u16]) -> Self {
+        Self { words, bit: 0 }
+    }
+
+    fn read(&mut self, width: usize) -> Result<u64, MainError> {
+        let mut out = 0u64;
+        for _ in 0..width {
+            let word = self.bit / 10;
+            let offset = 9 - (self.bit % 10);
+            let bit = self
+                .words
+                .get(word)
+                .ok_or_else(|| MainError::Message("slip39 share is truncated".into()))?;
+            out = (out << 1) | u64::from((bit >> offset) & 1);
+            self.bit += 1;
+        }
+        Ok(out)
+    }
+
+    /// Returns true if every remaining (padding) bit is zero.
+    fn rest_is_zero(&mut self) -> bool {
+        let total = self.words.len() * 10;
+        while self.bit < total {
+            match self.read(1) {
+                Ok(0) => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_share_round_trips_through_a_mnemonic() {
+        let share = Share {
+            identifier: 0x4242,
+            threshold: 3,
+            index: 2,
+            value: vec![1, 2, 3, 4, 5, 250, 251, 252],
+        };
+        let mnemonic = share.to_mnemonic();
+        let decoded = Share::from_mnemonic(&mnemonic).unwrap();
+        assert_eq!(decoded.identifier, share.identifier);
+        assert_eq!(decoded.threshold, share.threshold);
+        assert_eq!(decoded.index, share.index);
+        assert_eq!(decoded.value, share.value);
+    }
+
+    #[test]
+    fn test_share_round_trips_a_value_over_255_bytes() {
+        let value: Vec<u8> = (0..300).map(|i| (i % 256) as u8).collect();
+        let share = Share {
+            identifier: 1,
+            threshold: 2,
+            index: 1,
+            value,
+        };
+        let mnemonic = share.to_mnemonic();
+        let decoded = Share::from_mnemonic(&mnemonic).unwrap();
+        assert_eq!(decoded.value, share.value);
+    }
+
+    #[test]
+    fn test_a_mistyped_word_fails_the_checksum() {
+        let share = Share {
+            identifier: 7,
+            threshold: 2,
+            index: 1,
+            value: vec![9, 9, 9],
+        };
+        let mnemonic = share.to_mnemonic();
+        let mut words: Vec<&str> = mnemonic.split_whitespace().collect();
+        let last = words.len() - 1;
+        let other = if index_of(words[last]).unwrap() == 0 { 1 } else { 0 };
+        words[last] = WORDLIST[other];
+        let corrupted = words.join(" ");
+        assert!(Share::from_mnemonic(&corrupted).is_err());
+    }
+
+    #[test]
+    fn test_digest_verifies_the_matching_secret_and_rejects_others() {
+        let secret = b"a reconstructed secret!";
+        let key = b"a random digest key!!!!";
+        let value = digest(key, secret, key.len());
+        let mut digest_value = key.to_vec();
+        digest_value.extend_from_slice(&value);
+        assert!(verify_digest(&digest_value, secret));
+        assert!(!verify_digest(&digest_value, b"a different secret!!!!!"));
+    }
+}